@@ -70,9 +70,11 @@ impl CircuitInput {
     pub fn get_input_wire_count(&self) -> usize {
         self.input1_count + self.input2_count
     }
-    /// Number of inner labels you must supply: one per AND and one per NOT
+    /// Number of inner labels you must supply: one per NOT gate. AND gates no
+    /// longer draw a fresh zero-label; GRR3 defines it from the gate's pad
+    /// instead (see `garble_ckt`).
     pub fn get_inner_wire_label_count(&self) -> usize {
-        self.and_gate_count + self.not_gate_count
+        self.not_gate_count
     }
 }
 
@@ -94,8 +96,9 @@ struct AndGateTable {
     in0: usize,
     in1: usize,
     out: usize,
-    // four ciphertexts ordered (a=0,b=0) .. (1,1)
-    table: [ByteBuf; 4],
+    // GRR3: three ciphertexts for pointer-bit rows (0,1), (1,0), (1,1). The
+    // (0,0) row's ciphertext is zero by construction and is omitted.
+    table: [ByteBuf; 3],
 }
 
 #[derive(Serialize)]
@@ -112,6 +115,275 @@ pub struct GarbledOutput {
     // labels: Vec<[String; 2]>,
     and_tables: Vec<AndGateTable>,
     not_tables: Vec<NotGateTable>,
+    // which pad backend and circuit key were used to garble this circuit, so
+    // `evaluate_ckt` can recompute matching pads from just this struct.
+    pad_hash: PadHash,
+    circuit_key: [u8; 32],
+    // pointer bit of each output wire's zero-label, used to decode the
+    // evaluator's recovered output labels back into cleartext bits.
+    output_decoding: Vec<u8>,
+}
+
+/// Write `value` as an unsigned LEB128 varint: low 7 bits per byte,
+/// little-endian-first, with the high bit set on every byte but the last.
+fn write_uleb128(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// Read an unsigned LEB128 varint starting at `*pos`, advancing `*pos` past it.
+fn read_uleb128(buf: &[u8], pos: &mut usize) -> anyhow::Result<u64> {
+    let mut result: u64 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *buf
+            .get(*pos)
+            .ok_or_else(|| anyhow::anyhow!("truncated leb128 varint"))?;
+        *pos += 1;
+        result |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok(result)
+}
+
+/// Read exactly `n` raw bytes starting at `*pos`, advancing `*pos` past them.
+fn read_bytes<'a>(buf: &'a [u8], pos: &mut usize, n: usize) -> anyhow::Result<&'a [u8]> {
+    let end = *pos + n;
+    let bytes = buf
+        .get(*pos..end)
+        .ok_or_else(|| anyhow::anyhow!("truncated byte field"))?;
+    *pos = end;
+    Ok(bytes)
+}
+
+impl CircuitInput {
+    /// Compact encoding of `CircuitInput`: every `usize` field and gate wire
+    /// index as a LEB128 varint, instead of bincode's fixed-width integers.
+    pub fn encode_leb128(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_uleb128(&mut buf, self.total_gate_count as u64);
+        write_uleb128(&mut buf, self.and_gate_count as u64);
+        write_uleb128(&mut buf, self.xor_gate_count as u64);
+        write_uleb128(&mut buf, self.not_gate_count as u64);
+        write_uleb128(&mut buf, self.total_wire_count as u64);
+        write_uleb128(&mut buf, self.input1_count as u64);
+        write_uleb128(&mut buf, self.input2_count as u64);
+        write_uleb128(&mut buf, self.output_wire_count as u64);
+        for gate in &self.gates {
+            match *gate {
+                GateDef::And { in0, in1, out } => {
+                    buf.push(0);
+                    write_uleb128(&mut buf, in0 as u64);
+                    write_uleb128(&mut buf, in1 as u64);
+                    write_uleb128(&mut buf, out as u64);
+                }
+                GateDef::Xor { in0, in1, out } => {
+                    buf.push(1);
+                    write_uleb128(&mut buf, in0 as u64);
+                    write_uleb128(&mut buf, in1 as u64);
+                    write_uleb128(&mut buf, out as u64);
+                }
+                GateDef::Not { input, out } => {
+                    buf.push(2);
+                    write_uleb128(&mut buf, input as u64);
+                    write_uleb128(&mut buf, out as u64);
+                }
+            }
+        }
+        buf
+    }
+
+    /// Inverse of `encode_leb128`.
+    pub fn decode_leb128(buf: &[u8]) -> anyhow::Result<Self> {
+        let pos = &mut 0usize;
+        let total_gate_count = read_uleb128(buf, pos)? as usize;
+        let and_gate_count = read_uleb128(buf, pos)? as usize;
+        let xor_gate_count = read_uleb128(buf, pos)? as usize;
+        let not_gate_count = read_uleb128(buf, pos)? as usize;
+        let total_wire_count = read_uleb128(buf, pos)? as usize;
+        let input1_count = read_uleb128(buf, pos)? as usize;
+        let input2_count = read_uleb128(buf, pos)? as usize;
+        let output_wire_count = read_uleb128(buf, pos)? as usize;
+
+        let mut gates = Vec::with_capacity(total_gate_count);
+        for _ in 0..total_gate_count {
+            let tag = *buf
+                .get(*pos)
+                .ok_or_else(|| anyhow::anyhow!("truncated gate tag"))?;
+            *pos += 1;
+            let gate = match tag {
+                0 => GateDef::And {
+                    in0: read_uleb128(buf, pos)? as usize,
+                    in1: read_uleb128(buf, pos)? as usize,
+                    out: read_uleb128(buf, pos)? as usize,
+                },
+                1 => GateDef::Xor {
+                    in0: read_uleb128(buf, pos)? as usize,
+                    in1: read_uleb128(buf, pos)? as usize,
+                    out: read_uleb128(buf, pos)? as usize,
+                },
+                2 => GateDef::Not {
+                    input: read_uleb128(buf, pos)? as usize,
+                    out: read_uleb128(buf, pos)? as usize,
+                },
+                other => anyhow::bail!("unexpected gate tag `{}`", other),
+            };
+            gates.push(gate);
+        }
+
+        Ok(CircuitInput {
+            total_gate_count,
+            and_gate_count,
+            xor_gate_count,
+            not_gate_count,
+            total_wire_count,
+            input1_count,
+            input2_count,
+            output_wire_count,
+            gates,
+        })
+    }
+}
+
+impl GarbledOutput {
+    /// Compact encoding of `GarbledOutput`: gate/wire indices as LEB128
+    /// varints, 16-byte ciphertexts left as raw bytes.
+    pub fn encode_leb128(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_uleb128(&mut buf, self.and_tables.len() as u64);
+        for t in &self.and_tables {
+            write_uleb128(&mut buf, t.gate as u64);
+            write_uleb128(&mut buf, t.in0 as u64);
+            write_uleb128(&mut buf, t.in1 as u64);
+            write_uleb128(&mut buf, t.out as u64);
+            for ct in &t.table {
+                buf.extend_from_slice(ct);
+            }
+        }
+        write_uleb128(&mut buf, self.not_tables.len() as u64);
+        for t in &self.not_tables {
+            write_uleb128(&mut buf, t.gate as u64);
+            write_uleb128(&mut buf, t.input as u64);
+            write_uleb128(&mut buf, t.out as u64);
+            for ct in &t.table {
+                buf.extend_from_slice(ct);
+            }
+        }
+        buf.push(match self.pad_hash {
+            PadHash::Sha256 => 0,
+            PadHash::Blake3 => 1,
+        });
+        buf.extend_from_slice(&self.circuit_key);
+        write_uleb128(&mut buf, self.output_decoding.len() as u64);
+        buf.extend_from_slice(&self.output_decoding);
+        buf
+    }
+
+    /// Inverse of `encode_leb128`.
+    pub fn decode_leb128(buf: &[u8]) -> anyhow::Result<Self> {
+        let pos = &mut 0usize;
+        let and_count = read_uleb128(buf, pos)? as usize;
+        let mut and_tables = Vec::with_capacity(and_count);
+        for _ in 0..and_count {
+            let gate = read_uleb128(buf, pos)? as usize;
+            let in0 = read_uleb128(buf, pos)? as usize;
+            let in1 = read_uleb128(buf, pos)? as usize;
+            let out = read_uleb128(buf, pos)? as usize;
+            let mut table: [ByteBuf; 3] = Default::default();
+            for slot in table.iter_mut() {
+                *slot = ByteBuf::from(read_bytes(buf, pos, 16)?.to_vec());
+            }
+            and_tables.push(AndGateTable { gate, in0, in1, out, table });
+        }
+
+        let not_count = read_uleb128(buf, pos)? as usize;
+        let mut not_tables = Vec::with_capacity(not_count);
+        for _ in 0..not_count {
+            let gate = read_uleb128(buf, pos)? as usize;
+            let input = read_uleb128(buf, pos)? as usize;
+            let out = read_uleb128(buf, pos)? as usize;
+            let mut table: [ByteBuf; 2] = Default::default();
+            for slot in table.iter_mut() {
+                *slot = ByteBuf::from(read_bytes(buf, pos, 16)?.to_vec());
+            }
+            not_tables.push(NotGateTable { gate, input, out, table });
+        }
+
+        let pad_hash = match *buf
+            .get(*pos)
+            .ok_or_else(|| anyhow::anyhow!("truncated pad_hash tag"))?
+        {
+            0 => PadHash::Sha256,
+            1 => PadHash::Blake3,
+            other => anyhow::bail!("unexpected pad_hash tag `{}`", other),
+        };
+        *pos += 1;
+        let circuit_key: [u8; 32] = read_bytes(buf, pos, 32)?.try_into().unwrap();
+        let decoding_len = read_uleb128(buf, pos)? as usize;
+        let output_decoding = read_bytes(buf, pos, decoding_len)?.to_vec();
+
+        Ok(GarbledOutput {
+            and_tables,
+            not_tables,
+            pad_hash,
+            circuit_key,
+            output_decoding,
+        })
+    }
+}
+
+impl LabelInputs {
+    /// Compact encoding of `LabelInputs`: vector lengths as LEB128 varints,
+    /// labels left as raw bytes, matching `CircuitInput`/`GarbledOutput`.
+    pub fn encode_leb128(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&self.delta);
+        write_uleb128(&mut buf, self.input_labels.len() as u64);
+        for label in &self.input_labels {
+            buf.extend_from_slice(label);
+        }
+        write_uleb128(&mut buf, self.inner_labels.len() as u64);
+        for label in &self.inner_labels {
+            buf.extend_from_slice(label);
+        }
+        buf
+    }
+
+    /// Inverse of `encode_leb128`.
+    pub fn decode_leb128(buf: &[u8]) -> anyhow::Result<Self> {
+        let pos = &mut 0usize;
+        let delta: Label = read_bytes(buf, pos, 16)?.try_into().unwrap();
+
+        let input_count = read_uleb128(buf, pos)? as usize;
+        let mut input_labels = Vec::with_capacity(input_count);
+        for _ in 0..input_count {
+            input_labels.push(read_bytes(buf, pos, 16)?.try_into().unwrap());
+        }
+
+        let inner_count = read_uleb128(buf, pos)? as usize;
+        let mut inner_labels = Vec::with_capacity(inner_count);
+        for _ in 0..inner_count {
+            inner_labels.push(read_bytes(buf, pos, 16)?.try_into().unwrap());
+        }
+
+        Ok(LabelInputs {
+            delta,
+            input_labels,
+            inner_labels,
+        })
+    }
 }
 
 // this xors the 128 bit labels
@@ -123,6 +395,21 @@ fn xor_labels(a: &Label, b: &Label) -> Label {
     r
 }
 
+/// Which hash backend to use for the garbled-table masking pad.
+///
+/// `Sha256` is the original pad and is kept around for comparison/testing;
+/// it hashes only `ka || kb`, so two gates that happen to share both input
+/// wires produce identical table rows. `Blake3` fixes this by keying the
+/// hash per-circuit and folding the gate index and output wire into the
+/// input, so the pad is independent across distinct gates even when their
+/// input labels coincide.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PadHash {
+    Sha256,
+    #[default]
+    Blake3,
+}
+
 /// sha256-based pad: H(ka || kb)
 // This is used to get the masking value for the output gate labels
 // if two gates might share the same inputs, we need to append the gate_id to the value being hashed to get differnt table entries.
@@ -139,6 +426,57 @@ fn pad_sha(ka: &Label, kb: &Label) -> Label {
     out
 }
 
+/// Derive a per-circuit BLAKE3 key from `SEED` and the circuit's `delta`, so
+/// every circuit run uses an independently keyed pad even though `SEED` is fixed.
+fn blake3_circuit_key(delta: &Label) -> [u8; 32] {
+    let mut h = blake3::Hasher::new();
+    h.update(&SEED);
+    h.update(delta);
+    *h.finalize().as_bytes()
+}
+
+/// keyed BLAKE3 XOF pad for a binary gate (AND): H_k(ka || kb || gate_idx || out_wire).
+/// `gate_idx` and `out_wire` are domain-separating suffixes so gates that share
+/// input labels still get distinct pads.
+fn pad_blake3(key: &[u8; 32], ka: &Label, kb: &Label, gate_idx: usize, out_wire: usize) -> Label {
+    let mut h = blake3::Hasher::new_keyed(key);
+    h.update(ka);
+    h.update(kb);
+    h.update(&gate_idx.to_le_bytes());
+    h.update(&out_wire.to_le_bytes());
+    let mut out = [0u8; 16];
+    h.finalize_xof().fill(&mut out);
+    out
+}
+
+/// keyed BLAKE3 XOF pad for a unary gate (NOT): hashes only the single input
+/// label instead of duplicating it into both halves like `pad_sha` does.
+fn pad_blake3_unary(key: &[u8; 32], ka: &Label, gate_idx: usize, out_wire: usize) -> Label {
+    let mut h = blake3::Hasher::new_keyed(key);
+    h.update(ka);
+    h.update(&gate_idx.to_le_bytes());
+    h.update(&out_wire.to_le_bytes());
+    let mut out = [0u8; 16];
+    h.finalize_xof().fill(&mut out);
+    out
+}
+
+/// Compute the masking pad for a binary (AND) gate row under the chosen backend.
+fn pad_binary(pad_hash: PadHash, key: &[u8; 32], ka: &Label, kb: &Label, gate_idx: usize, out_wire: usize) -> Label {
+    match pad_hash {
+        PadHash::Sha256 => pad_sha(ka, kb),
+        PadHash::Blake3 => pad_blake3(key, ka, kb, gate_idx, out_wire),
+    }
+}
+
+/// Compute the masking pad for a unary (NOT) gate row under the chosen backend.
+fn pad_unary(pad_hash: PadHash, key: &[u8; 32], ka: &Label, gate_idx: usize, out_wire: usize) -> Label {
+    match pad_hash {
+        PadHash::Sha256 => pad_sha(ka, ka),
+        PadHash::Blake3 => pad_blake3_unary(key, ka, gate_idx, out_wire),
+    }
+}
+
 /// Parse a minimal subset of Bristol format: assumes each gate line is:
 ///     <AND/XOR> <u> <v> <o>
 /// or, <INV> <u> <o>
@@ -226,7 +564,7 @@ fn parse_bristol<P: AsRef<Path>>(path: P) -> anyhow::Result<CircuitInput> {
 }
 
 
-pub fn garble_ckt(ckt_inputs: CircuitInput, label_inputs: LabelInputs) -> GarbledOutput {
+pub fn garble_ckt(ckt_inputs: CircuitInput, label_inputs: LabelInputs, pad_hash: PadHash) -> GarbledOutput {
     let wcnt = ckt_inputs.total_wire_count;
     let gcnt = ckt_inputs.total_gate_count;
     let in1 = ckt_inputs.input1_count;
@@ -234,6 +572,7 @@ pub fn garble_ckt(ckt_inputs: CircuitInput, label_inputs: LabelInputs) -> Garble
     let gates = ckt_inputs.gates;
 
     let delta = label_inputs.delta;
+    let circuit_key = blake3_circuit_key(&delta);
     let mut inner_iter = label_inputs.inner_labels.into_iter();
 
     // pre-allocate wire slots
@@ -270,22 +609,46 @@ pub fn garble_ckt(ckt_inputs: CircuitInput, label_inputs: LabelInputs) -> Garble
                 let lu = wires[in0].clone().unwrap();
                 let lv = wires[in1].clone().unwrap();
 
-                let k0_out = inner_iter.next().unwrap();
-                let k1_out = xor_labels(&k0_out, &delta);
-                wires[out] = Some(WireLabels { k0: k0_out, k1: k1_out });
+                // point-and-permute: ptr(lu.k0)/ptr(lv.k0) tell us which of
+                // the two labels on each wire carries pointer bit 0, so the
+                // table can be indexed by pointer bits instead of by the
+                // semantic (a, b) truth-table position.
+                let pu0 = ptr_bit(&lu.k0);
+                let pv0 = ptr_bit(&lv.k0);
 
+                // GRR3: instead of drawing a fresh zero-label for the output
+                // wire, *define* it from the pad of the (0,0)-pointer row, so
+                // that row's ciphertext is all-zero and can be omitted.
+                let ka00 = if pu0 == 0 { lu.k0 } else { lu.k1 };
+                let kb00 = if pv0 == 0 { lv.k0 } else { lv.k1 };
+                let a00 = (0 != pu0) as u8;
+                let b00 = (0 != pv0) as u8;
+                let p00 = pad_binary(pad_hash, &circuit_key, &ka00, &kb00, idx, out);
+                let other00 = xor_labels(&p00, &delta);
+                let (k0_out, k1_out) = if a00 & b00 == 0 {
+                    (p00, other00)
+                } else {
+                    (other00, p00)
+                };
+                wires[out] = Some(WireLabels { k0: k0_out, k1: k1_out });
 
-                let mut table: [ByteBuf; 4] = Default::default();
-                let combos = [(0u8, 0u8), (0, 1), (1, 0), (1, 1)];
-                for (i, (a, b)) in combos.iter().enumerate() {
-                    let ka = if *a == 0 { lu.k0 } else { lu.k1 };
-                    let kb = if *b == 0 { lv.k0 } else { lv.k1 };
-                    let out_bit = a & b;
-                    let kout = if out_bit == 0 { k0_out } else { k1_out };
-                    let p = pad_sha(&ka, &kb);
-                    // let p = pad_poseidon(&ka, &kb);
-                    let ct = xor_labels(&p, &kout);
-                    table[i] = ByteBuf::from(ct.to_vec());
+                let mut table: [ByteBuf; 3] = Default::default();
+                for pa in 0u8..2 {
+                    for pb in 0u8..2 {
+                        if pa == 0 && pb == 0 {
+                            // row omitted: the evaluator recomputes p00 directly
+                            continue;
+                        }
+                        let a = (pa != pu0) as u8;
+                        let b = (pb != pv0) as u8;
+                        let ka = if a == 0 { lu.k0 } else { lu.k1 };
+                        let kb = if b == 0 { lv.k0 } else { lv.k1 };
+                        let out_bit = a & b;
+                        let kout = if out_bit == 0 { k0_out } else { k1_out };
+                        let p = pad_binary(pad_hash, &circuit_key, &ka, &kb, idx, out);
+                        let ct = xor_labels(&p, &kout);
+                        table[(pa * 2 + pb - 1) as usize] = ByteBuf::from(ct.to_vec());
+                    }
                 }
 
                 and_tables.push(AndGateTable {
@@ -305,16 +668,17 @@ pub fn garble_ckt(ckt_inputs: CircuitInput, label_inputs: LabelInputs) -> Garble
                 let k1_out = xor_labels(&k0_out, &delta);
                 wires[out] = Some(WireLabels { k0: k0_out, k1: k1_out });
 
+                let pu0 = ptr_bit(&lu.k0);
 
                 let mut table: [ByteBuf; 2] = Default::default();
-                for (i, &a) in [0u8, 1].iter().enumerate() {
+                for p in 0u8..2 {
+                    let a = (p != pu0) as u8;
                     let ka = if a == 0 { lu.k0 } else { lu.k1 };
                     let out_bit = 1 - a;
                     let kout = if out_bit == 0 { k0_out } else { k1_out };
-                    let p = pad_sha(&ka, &ka); // unary, duplicate
-                    // let p = pad_poseidon(&ka, &ka);
-                    let ct = xor_labels(&p, &kout);
-                    table[i] = ByteBuf::from(ct.to_vec());
+                    let pad = pad_unary(pad_hash, &circuit_key, &ka, idx, out);
+                    let ct = xor_labels(&pad, &kout);
+                    table[p as usize] = ByteBuf::from(ct.to_vec());
                 }
 
                 not_tables.push(NotGateTable {
@@ -327,6 +691,12 @@ pub fn garble_ckt(ckt_inputs: CircuitInput, label_inputs: LabelInputs) -> Garble
         }
     }
 
+    // decoding table: pointer bit of each output wire's zero-label, so the
+    // evaluator can map a recovered output label back to a cleartext bit.
+    let output_decoding: Vec<u8> = ((wcnt - ckt_inputs.output_wire_count)..wcnt)
+        .map(|w| ptr_bit(&wires[w].as_ref().unwrap().k0))
+        .collect();
+
     // // Collect human-readable input labels
     // let mut input_labels = Vec::with_capacity(in1 + in2);
     // for i in 0..(in1 + in2) {
@@ -346,7 +716,80 @@ pub fn garble_ckt(ckt_inputs: CircuitInput, label_inputs: LabelInputs) -> Garble
         // labels: input_labels,
         and_tables,
         not_tables,
+        pad_hash,
+        circuit_key,
+        output_decoding,
+    }
+}
+
+/// pointer bit of a label under point-and-permute: the LSB of its first byte.
+/// `delta & 1 == 1` (enforced in `gen_labels`) guarantees this bit is
+/// complementary between a wire's two labels.
+fn ptr_bit(label: &Label) -> u8 {
+    label[0] & 1
+}
+
+/// Evaluate a garbled circuit given one label per input wire, returning one
+/// recovered label per output wire. XOR gates propagate via free-XOR; AND/NOT
+/// gates use the pointer bits of the held labels to pick the matching
+/// ciphertext and recompute the pad with the backend recorded in `garbled`.
+pub fn evaluate_ckt(garbled: &GarbledOutput, ckt: &CircuitInput, input_labels: Vec<Label>) -> Vec<Label> {
+    let wcnt = ckt.total_wire_count;
+    let mut wires: Vec<Option<Label>> = vec![None; wcnt];
+    for (i, label) in input_labels.into_iter().enumerate() {
+        wires[i] = Some(label);
+    }
+
+    let mut and_iter = garbled.and_tables.iter();
+    let mut not_iter = garbled.not_tables.iter();
+
+    for gate in &ckt.gates {
+        match *gate {
+            GateDef::Xor { in0, in1, out } => {
+                let ka = wires[in0].unwrap();
+                let kb = wires[in1].unwrap();
+                wires[out] = Some(xor_labels(&ka, &kb));
+            }
+            GateDef::And { in0, in1, out } => {
+                let ka = wires[in0].unwrap();
+                let kb = wires[in1].unwrap();
+                let t = and_iter.next().expect("missing AND table for gate");
+                let row = (ptr_bit(&ka) * 2 + ptr_bit(&kb)) as usize;
+                let p = pad_binary(garbled.pad_hash, &garbled.circuit_key, &ka, &kb, t.gate, out);
+                // GRR3: the (0,0)-pointer row has no ciphertext; its output
+                // label is the pad itself.
+                let kout = if row == 0 {
+                    p
+                } else {
+                    let ct: Label = t.table[row - 1].as_slice().try_into().unwrap();
+                    xor_labels(&p, &ct)
+                };
+                wires[out] = Some(kout);
+            }
+            GateDef::Not { input, out } => {
+                let ka = wires[input].unwrap();
+                let t = not_iter.next().expect("missing NOT table for gate");
+                let row = ptr_bit(&ka) as usize;
+                let p = pad_unary(garbled.pad_hash, &garbled.circuit_key, &ka, t.gate, out);
+                let ct: Label = t.table[row].as_slice().try_into().unwrap();
+                wires[out] = Some(xor_labels(&p, &ct));
+            }
+        }
     }
+
+    let out_start = wcnt - ckt.output_wire_count;
+    (out_start..wcnt).map(|w| wires[w].unwrap()).collect()
+}
+
+/// Decode recovered output labels into cleartext bits using the garbled
+/// circuit's decoding table: a bit is 0 iff the label's pointer bit matches
+/// the zero-label's pointer bit recorded at garbling time.
+pub fn decode_output(garbled: &GarbledOutput, output_labels: &[Label]) -> Vec<bool> {
+    output_labels
+        .iter()
+        .zip(garbled.output_decoding.iter())
+        .map(|(label, &zero_ptr)| ptr_bit(label) != zero_ptr)
+        .collect()
 }
 
     //read the circuit
@@ -364,6 +807,9 @@ pub fn gen_labels(input_wire_count: usize, inner_wire_count: usize) -> LabelInpu
     //initialize delta with random value. this is the global offset required for free-xor
     let mut delta = [0u8; 16];
     rng.fill_bytes(&mut delta);
+    // point-and-permute: force delta's LSB to 1 so a wire's two labels
+    // (k1 = k0 ^ delta) always have complementary pointer bits.
+    delta[0] |= 1;
 
     let mut input_labels = Vec::with_capacity(input_wire_count);
      for _ in 0..input_wire_count {
@@ -380,10 +826,221 @@ pub fn gen_labels(input_wire_count: usize, inner_wire_count: usize) -> LabelInpu
          inner_labels.push(k0);
      }
 
-    LabelInputs { 
+    LabelInputs {
         delta,
         input_labels,
         inner_labels,
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Wire indices large enough to need multi-byte LEB128 varints; not meant
+    // to be garbled/evaluated, only round-tripped through the codec.
+    fn sample_ckt() -> CircuitInput {
+        CircuitInput {
+            total_gate_count: 3,
+            and_gate_count: 1,
+            xor_gate_count: 1,
+            not_gate_count: 1,
+            total_wire_count: 70001,
+            input1_count: 2,
+            input2_count: 2,
+            output_wire_count: 1,
+            gates: vec![
+                GateDef::And { in0: 0, in1: 1, out: 4 },
+                GateDef::Xor { in0: 2, in1: 3, out: 300 },
+                GateDef::Not { input: 300, out: 70000 },
+            ],
+        }
+    }
+
+    // A small, actually-executable circuit: in0=0,in1=1 (garbler inputs),
+    // in2=2,in3=3 (evaluator inputs); wire 4 = AND(0,1); wire 5 = XOR(2,3);
+    // wire 6 = NOT(5), the sole output wire.
+    fn executable_ckt() -> CircuitInput {
+        CircuitInput {
+            total_gate_count: 3,
+            and_gate_count: 1,
+            xor_gate_count: 1,
+            not_gate_count: 1,
+            total_wire_count: 7,
+            input1_count: 2,
+            input2_count: 2,
+            output_wire_count: 1,
+            gates: vec![
+                GateDef::And { in0: 0, in1: 1, out: 4 },
+                GateDef::Xor { in0: 2, in1: 3, out: 5 },
+                GateDef::Not { input: 5, out: 6 },
+            ],
+        }
+    }
+
+    // A bare AND gate, so the GRR3 row-reduced table's output can be checked
+    // directly against the truth table, independent of XOR/NOT propagation.
+    fn and_only_ckt() -> CircuitInput {
+        CircuitInput {
+            total_gate_count: 1,
+            and_gate_count: 1,
+            xor_gate_count: 0,
+            not_gate_count: 0,
+            total_wire_count: 3,
+            input1_count: 1,
+            input2_count: 1,
+            output_wire_count: 1,
+            gates: vec![GateDef::And { in0: 0, in1: 1, out: 2 }],
+        }
+    }
+
+    #[test]
+    fn grr3_and_gate_matches_truth_table() {
+        for a in 0u8..2 {
+            for b in 0u8..2 {
+                let ckt = and_only_ckt();
+                let labels = gen_labels(ckt.get_input_wire_count(), ckt.get_inner_wire_label_count());
+                let zero_labels = labels.input_labels.clone();
+                let delta = labels.delta;
+                let garbled = garble_ckt(ckt, labels, PadHash::Blake3);
+
+                let active_labels = vec![
+                    if a == 0 { zero_labels[0] } else { xor_labels(&zero_labels[0], &delta) },
+                    if b == 0 { zero_labels[1] } else { xor_labels(&zero_labels[1], &delta) },
+                ];
+
+                let ckt = and_only_ckt();
+                let output_labels = evaluate_ckt(&garbled, &ckt, active_labels);
+                let bits = decode_output(&garbled, &output_labels);
+
+                assert_eq!(bits, vec![a & b == 1]);
+                // GRR3's row-reduced table has three ciphertexts, not four.
+                assert_eq!(garbled.and_tables[0].table.len(), 3);
+            }
+        }
+    }
+
+    #[test]
+    fn circuit_input_leb128_round_trips() {
+        let ckt = sample_ckt();
+        let bytes = ckt.encode_leb128();
+        let decoded = CircuitInput::decode_leb128(&bytes).unwrap();
+        assert_eq!(ckt.total_gate_count, decoded.total_gate_count);
+        assert_eq!(ckt.and_gate_count, decoded.and_gate_count);
+        assert_eq!(ckt.xor_gate_count, decoded.xor_gate_count);
+        assert_eq!(ckt.not_gate_count, decoded.not_gate_count);
+        assert_eq!(ckt.total_wire_count, decoded.total_wire_count);
+        assert_eq!(ckt.input1_count, decoded.input1_count);
+        assert_eq!(ckt.input2_count, decoded.input2_count);
+        assert_eq!(ckt.output_wire_count, decoded.output_wire_count);
+        assert_eq!(format!("{:?}", ckt.gates), format!("{:?}", decoded.gates));
+    }
+
+    #[test]
+    fn garbled_output_leb128_round_trips() {
+        let ckt = executable_ckt();
+        let labels = gen_labels(ckt.get_input_wire_count(), ckt.get_inner_wire_label_count());
+        let out = garble_ckt(ckt, labels, PadHash::Blake3);
+
+        let bytes = out.encode_leb128();
+        let decoded = GarbledOutput::decode_leb128(&bytes).unwrap();
+
+        assert_eq!(out.and_tables.len(), decoded.and_tables.len());
+        for (a, b) in out.and_tables.iter().zip(decoded.and_tables.iter()) {
+            assert_eq!(a.gate, b.gate);
+            assert_eq!(a.in0, b.in0);
+            assert_eq!(a.in1, b.in1);
+            assert_eq!(a.out, b.out);
+            assert_eq!(a.table, b.table);
+        }
+
+        assert_eq!(out.not_tables.len(), decoded.not_tables.len());
+        for (a, b) in out.not_tables.iter().zip(decoded.not_tables.iter()) {
+            assert_eq!(a.gate, b.gate);
+            assert_eq!(a.input, b.input);
+            assert_eq!(a.out, b.out);
+            assert_eq!(a.table, b.table);
+        }
+
+        assert_eq!(out.pad_hash, decoded.pad_hash);
+        assert_eq!(out.circuit_key, decoded.circuit_key);
+        assert_eq!(out.output_decoding, decoded.output_decoding);
+    }
+
+    #[test]
+    fn blake3_pad_is_domain_separated_across_gates() {
+        let key = blake3_circuit_key(&[7u8; 16]);
+        let ka = [1u8; 16];
+        let kb = [2u8; 16];
+
+        // Same input labels, different gate index: the headline bug this
+        // request fixes is that `pad_sha` collides here (it only hashes
+        // `ka || kb`), while `pad_blake3` must not.
+        let row_gate0 = pad_blake3(&key, &ka, &kb, 0, 0);
+        let row_gate1 = pad_blake3(&key, &ka, &kb, 1, 0);
+        assert_ne!(row_gate0, row_gate1);
+
+        // Same gate, different output wire, also must not collide.
+        let row_out0 = pad_blake3(&key, &ka, &kb, 0, 0);
+        let row_out1 = pad_blake3(&key, &ka, &kb, 0, 1);
+        assert_ne!(row_out0, row_out1);
+
+        // The SHA-256 pad, by contrast, ignores gate/wire indices entirely
+        // and does collide across gates that share input labels.
+        assert_eq!(pad_sha(&ka, &kb), pad_sha(&ka, &kb));
+    }
+
+    #[test]
+    fn label_inputs_leb128_round_trips() {
+        let ckt = executable_ckt();
+        let labels = gen_labels(ckt.get_input_wire_count(), ckt.get_inner_wire_label_count());
+
+        let bytes = labels.encode_leb128();
+        let decoded = LabelInputs::decode_leb128(&bytes).unwrap();
+
+        assert_eq!(labels.delta, decoded.delta);
+        assert_eq!(labels.input_labels, decoded.input_labels);
+        assert_eq!(labels.inner_labels, decoded.inner_labels);
+    }
+
+    #[test]
+    fn delta_lsb_is_forced_to_one() {
+        let labels = gen_labels(4, 2);
+        assert_eq!(labels.delta[0] & 1, 1);
+    }
+
+    #[test]
+    fn evaluate_ckt_matches_plaintext_for_all_inputs() {
+        for a in 0u8..2 {
+            for b in 0u8..2 {
+                for c in 0u8..2 {
+                    for d in 0u8..2 {
+                        let ckt = executable_ckt();
+                        let labels = gen_labels(ckt.get_input_wire_count(), ckt.get_inner_wire_label_count());
+                        let wire_labels = |bit: u8, k0: &Label, delta: &Label| {
+                            if bit == 0 { *k0 } else { xor_labels(k0, delta) }
+                        };
+                        let zero_labels = labels.input_labels.clone();
+                        let delta = labels.delta;
+                        let garbled = garble_ckt(ckt, labels, PadHash::Blake3);
+
+                        let active_labels = vec![
+                            wire_labels(a, &zero_labels[0], &delta),
+                            wire_labels(b, &zero_labels[1], &delta),
+                            wire_labels(c, &zero_labels[2], &delta),
+                            wire_labels(d, &zero_labels[3], &delta),
+                        ];
+
+                        let ckt = executable_ckt();
+                        let output_labels = evaluate_ckt(&garbled, &ckt, active_labels);
+                        let bits = decode_output(&garbled, &output_labels);
+
+                        let expected = (c ^ d) == 0;
+                        assert_eq!(bits, vec![expected]);
+                    }
+                }
+            }
+        }
+    }
+}
+
@@ -12,18 +12,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use garbling_core::{CircuitInput, LabelInputs, garble_ckt};
+use garbling_core::{CircuitInput, LabelInputs, PadHash, garble_ckt};
 use risc0_zkvm::guest::env;
 
-fn main(){
-   let circuit_input: CircuitInput = env::read();
+/// Read a `u32`-length-prefixed byte buffer written by the host.
+fn read_framed_bytes() -> Vec<u8> {
+    let len: u32 = env::read();
+    let mut buf = vec![0u8; len as usize];
+    env::read_slice(&mut buf);
+    buf
+}
 
-   let label_input:LabelInputs = env::read();
+fn main(){
+   // The host writes `CircuitInput`/`LabelInputs` as LEB128-packed byte
+   // buffers rather than full-width serde structs, so both the input read
+   // here and the output committed below stay close to their information
+   // content instead of paying for fixed-width `usize`s.
+   let circuit_input = CircuitInput::decode_leb128(&read_framed_bytes()).unwrap();
 
-   let out = garble_ckt(circuit_input, label_input);
+   let label_input = LabelInputs::decode_leb128(&read_framed_bytes()).unwrap();
 
-   env::commit(&out);
+   // BLAKE3's single-pass XOF needs fewer hash compressions per gate than the
+   // SHA-256 truncation path, so it's the default for the zkVM guest.
+   let out = garble_ckt(circuit_input, label_input, PadHash::Blake3);
 
-    
+   env::commit_slice(&out.encode_leb128());
 }
 
@@ -1,9 +1,38 @@
 use risc0_zkvm::sha::Digest;
 use serde::{Deserialize, Serialize};
 
+/// A comparison to run against a single field's value.
+///
+/// `Eq`/`Neq` compare file1's value for the field against file2's value for
+/// the same field. `Lt`/`Gt`/`Range` compare file1's value against a
+/// caller-supplied threshold, independent of file2.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Predicate {
+    Eq,
+    Neq,
+    Lt(i64),
+    Gt(i64),
+    Range { low: i64, high: i64 },
+}
+
+/// One field to check: a dotted path into the parsed JSON (e.g. `"a.b.c"`)
+/// together with the predicate to evaluate on it.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FieldCheck {
+    pub selector: String,
+    pub predicate: Predicate,
+}
+
+/// The outcome of evaluating one `FieldCheck`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FieldResult {
+    pub selector: String,
+    pub satisfied: bool,
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Outputs {
     pub file1hash: Digest,
     pub file2hash: Digest,
-    pub have_same_critical_val: bool,
-}
\ No newline at end of file
+    pub results: Vec<FieldResult>,
+}
@@ -4,7 +4,7 @@ use methods::{
     GUEST_JSON_CMP_ELF, GUEST_JSON_CMP_ID
 };
 use risc0_zkvm::{default_prover, ExecutorEnv};
-use json_core::Outputs;
+use json_core::{FieldCheck, Outputs, Predicate};
 
 fn main() {
     // Initialize tracing. In order to view logs, run `RUST_LOG=info cargo run`
@@ -28,7 +28,15 @@ fn main() {
     let file1 = include_str!("../../res/file1.json").to_string();
     let file2 = include_str!("../../res/file2.json").to_string();
 
-    let input = (file1,file2);
+    // the fields to check and the predicate to apply to each; e.g. this
+    // proves both files agree on `critical_data` without revealing either
+    // file's contents.
+    let checks = vec![FieldCheck {
+        selector: "critical_data".to_string(),
+        predicate: Predicate::Eq,
+    }];
+
+    let input = (file1, file2, checks);
 
     let env = ExecutorEnv::builder()
         .write(&input)
@@ -51,10 +59,12 @@ fn main() {
 
     let out: Outputs = receipt.journal.decode().unwrap();
 
-    if out.have_same_critical_val{
-        println!("file1 with hash {} \n and file2 with hash {} both contain the same value in the field 'critical value'",out.file1hash,out.file2hash);
-    }else{
-        println!("file1 with hash {} \n and file2 with hash {} do NOT contain the same value in the field 'critical value'",out.file1hash,out.file2hash);
+    for result in &out.results {
+        if result.satisfied {
+            println!("file1 with hash {} \n and file2 with hash {} satisfy the predicate on field '{}'",out.file1hash,out.file2hash,result.selector);
+        }else{
+            println!("file1 with hash {} \n and file2 with hash {} do NOT satisfy the predicate on field '{}'",out.file1hash,out.file2hash,result.selector);
+        }
     }
 
     // The receipt was verified at the end of proving, but the below code is an
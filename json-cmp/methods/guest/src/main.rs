@@ -1,14 +1,46 @@
-use json::parse;
-use json_core::Outputs;
+use json::{parse, JsonValue};
+use json_core::{FieldCheck, FieldResult, Outputs, Predicate};
 use risc0_zkvm::{
     guest::env,
     sha::{Impl, Sha256},
 };
 
+/// Walk a dotted path (e.g. "a.b.c") into a parsed JSON document, returning
+/// `None` if any segment is missing along the way. A segment whose value is
+/// present but literally `null` is *not* missing — `has_key` is what tells
+/// "absent" and "present-and-null" apart, since indexing alone (`cur[part]`)
+/// returns `JsonValue::Null` for both.
+fn get_path<'a>(value: &'a JsonValue, selector: &str) -> Option<&'a JsonValue> {
+    let mut cur = value;
+    for part in selector.split('.') {
+        if !cur.has_key(part) {
+            return None;
+        }
+        cur = &cur[part];
+    }
+    Some(cur)
+}
+
+fn evaluate(file1: &JsonValue, file2: &JsonValue, check: &FieldCheck) -> bool {
+    let v1 = get_path(file1, &check.selector);
+    let v2 = get_path(file2, &check.selector);
+    match check.predicate {
+        // A field that's missing from either file can't be claimed as equal
+        // or unequal: both sides must actually resolve before we compare.
+        Predicate::Eq => matches!((v1, v2), (Some(a), Some(b)) if a == b),
+        Predicate::Neq => matches!((v1, v2), (Some(a), Some(b)) if a != b),
+        Predicate::Lt(threshold) => v1.and_then(|v| v.as_i64()).is_some_and(|n| n < threshold),
+        Predicate::Gt(threshold) => v1.and_then(|v| v.as_i64()).is_some_and(|n| n > threshold),
+        Predicate::Range { low, high } => v1
+            .and_then(|v| v.as_i64())
+            .is_some_and(|n| n >= low && n <= high),
+    }
+}
+
 fn main() {
     //read data from the environment.
     //This is the data that was given to the guest program by host.
-    let data : (String,String)= env::read();
+    let data: (String, String, Vec<FieldCheck>) = env::read();
 
     //get the hash of the two files
     let file1_hash = *Impl::hash_bytes(&data.0.as_bytes());
@@ -18,20 +50,129 @@ fn main() {
     let file1_contents = parse(&data.0).unwrap();
     let file2_contents = parse(&data.1).unwrap();
 
-    //get the critical data field from both the files
-    let file1_critcalcontent = file1_contents["critical_data"].as_u32().unwrap();
-    let file2_critcalcontent = file2_contents["critical_data"].as_u32().unwrap();
-
-    let have_same_critical_val = file1_critcalcontent == file2_critcalcontent;
+    //evaluate each requested field check against the parsed documents
+    let results = data
+        .2
+        .iter()
+        .map(|check| FieldResult {
+            selector: check.selector.clone(),
+            satisfied: evaluate(&file1_contents, &file2_contents, check),
+        })
+        .collect();
 
     //commit to the Outputs
     let out = Outputs {
         file1hash: file1_hash,
         file2hash: file2_hash,
-        have_same_critical_val,
+        results,
     };
 
-
     // write public output to the journal
     env::commit(&out);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn docs() -> (JsonValue, JsonValue) {
+        let file1 = parse(
+            r#"{"a": {"b": 1, "c": null}, "critical_data": "x", "score": 5}"#,
+        )
+        .unwrap();
+        let file2 = parse(
+            r#"{"a": {"b": 1}, "critical_data": "x", "score": 9}"#,
+        )
+        .unwrap();
+        (file1, file2)
+    }
+
+    #[test]
+    fn get_path_distinguishes_missing_from_null() {
+        let (file1, _file2) = docs();
+        // present and null: a real value, not absent
+        assert_eq!(get_path(&file1, "a.c"), Some(&JsonValue::Null));
+        // genuinely absent: no such key anywhere in either document
+        assert_eq!(get_path(&file1, "a.d"), None);
+        assert_eq!(get_path(&file1, "nope"), None);
+    }
+
+    #[test]
+    fn eq_is_not_satisfied_when_field_is_missing_from_both_files() {
+        let (file1, file2) = docs();
+        // `a.d` is absent from both files; a vacuous "None == None" must not
+        // be reported as the files agreeing on the field.
+        let check = FieldCheck {
+            selector: "a.d".to_string(),
+            predicate: Predicate::Eq,
+        };
+        assert!(!evaluate(&file1, &file2, &check));
+    }
+
+    #[test]
+    fn eq_and_neq_require_both_sides_present() {
+        let (file1, file2) = docs();
+        // present-and-null vs. missing: neither Eq nor Neq should fire.
+        let eq = FieldCheck { selector: "a.c".to_string(), predicate: Predicate::Eq };
+        let neq = FieldCheck { selector: "a.c".to_string(), predicate: Predicate::Neq };
+        assert!(!evaluate(&file1, &file2, &eq));
+        assert!(!evaluate(&file1, &file2, &neq));
+    }
+
+    #[test]
+    fn eq_is_satisfied_when_both_sides_actually_match() {
+        let (file1, file2) = docs();
+        let check = FieldCheck {
+            selector: "critical_data".to_string(),
+            predicate: Predicate::Eq,
+        };
+        assert!(evaluate(&file1, &file2, &check));
+    }
+
+    #[test]
+    fn lt_gt_range_are_false_on_missing_values() {
+        let (file1, file2) = docs();
+        for predicate in [Predicate::Lt(10), Predicate::Gt(0), Predicate::Range { low: 0, high: 10 }] {
+            let check = FieldCheck { selector: "a.d".to_string(), predicate };
+            assert!(!evaluate(&file1, &file2, &check));
+        }
+    }
+
+    #[test]
+    fn lt_gt_range_are_false_on_non_numeric_values() {
+        let (file1, file2) = docs();
+        for predicate in [Predicate::Lt(10), Predicate::Gt(0), Predicate::Range { low: 0, high: 10 }] {
+            let check = FieldCheck { selector: "critical_data".to_string(), predicate };
+            assert!(!evaluate(&file1, &file2, &check));
+        }
+    }
+
+    #[test]
+    fn lt_gt_range_evaluate_numeric_thresholds() {
+        let (file1, file2) = docs();
+        let lt = FieldCheck { selector: "score".to_string(), predicate: Predicate::Lt(10) };
+        let gt = FieldCheck { selector: "score".to_string(), predicate: Predicate::Gt(8) };
+        let range = FieldCheck {
+            selector: "score".to_string(),
+            predicate: Predicate::Range { low: 0, high: 3 },
+        };
+        assert!(evaluate(&file1, &file2, &lt));
+        assert!(evaluate(&file2, &file1, &gt));
+        assert!(!evaluate(&file1, &file2, &range));
+    }
+
+    #[test]
+    fn multi_field_selector_list_evaluates_independently() {
+        let (file1, file2) = docs();
+        let checks = vec![
+            FieldCheck { selector: "critical_data".to_string(), predicate: Predicate::Eq },
+            FieldCheck { selector: "score".to_string(), predicate: Predicate::Eq },
+            FieldCheck { selector: "a.d".to_string(), predicate: Predicate::Eq },
+        ];
+        let results: Vec<bool> = checks
+            .iter()
+            .map(|check| evaluate(&file1, &file2, check))
+            .collect();
+        assert_eq!(results, vec![true, false, false]);
+    }
+}